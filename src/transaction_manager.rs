@@ -1,90 +1,306 @@
 use ethers::{prelude::*, types::transaction::eip2718::TypedTransaction};
-use eyre::{Report, Result};
+use eyre::{eyre, Report, Result};
 use log::{debug, error, info};
-use std::{sync::Arc, time::Duration};
-use tokio::time::sleep;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::{sync::Mutex, time::sleep};
 
 // Constants for retry strategy
 const MAX_RETRIES: u32 = 2;
 const RETRY_DELAY: Duration = Duration::from_secs(5);
 
+// Constants for EIP-1559 fee estimation
+const FEE_HISTORY_BLOCKS: u64 = 10;
+const FEE_HISTORY_REWARD_PERCENTILE: f64 = 50.0;
+
+// Default share of the queue's total capacity a single sender may occupy, so one account can't
+// starve the rest.
+const DEFAULT_PER_SENDER_SHARE: f64 = 0.2;
+
+// How often we check whether a broadcast transaction has been mined yet.
+const RECEIPT_POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+// How many times `TransactionQueue::drain` will dispatch a transaction that keeps failing for a
+// transient reason before giving up on it (so it is re-queued at most one time fewer than this),
+// so a persistently-failing entry can't be redispatched forever.
+const QUEUE_MAX_DISPATCH_ATTEMPTS: u32 = 3;
+
+// Substrings that mark a send error as permanent (retrying with the same intent cannot help),
+// as opposed to transient errors like timeouts or nonce drift.
+const PERMANENT_ERROR_MARKERS: &[&str] = &[
+    "revert",
+    "insufficient funds",
+    "invalid chain id",
+    "intrinsic gas too low",
+    "exceeds block gas limit",
+    // Raised by `wait_with_escalation` once gas-price escalation is exhausted. Treated as
+    // permanent (rather than retried) so `send_with_nonce` doesn't blindly re-broadcast a fresh
+    // transaction at the same nonce while the original, still-unconfirmed broadcast might yet
+    // mine; the resync this triggers will pick up whatever the chain actually did once it's
+    // known, rather than racing it.
+    "gas escalation exhausted",
+];
+
+/// The outcome of a transaction that reached `num_confirmations` confirmations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionOutcome {
+    pub tx_hash: TxHash,
+    pub block_number: U64,
+    pub confirmations: usize,
+    pub status: TransactionStatus,
+}
+
+/// Whether a mined transaction's execution succeeded or reverted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionStatus {
+    Success,
+    Reverted,
+}
+
+/// A signer-bound client for a single wallet in a [`WalletPool`].
+type SignerClient = SignerMiddleware<Arc<Provider<Http>>, LocalWallet>;
+
+/// A pool of wallets with a locally-cached nonce per wallet, so concurrent senders can hand out
+/// nonces with an atomic `fetch_add` instead of re-deriving them from `num_transactions +
+/// attempts` guesswork, which races when two callers read the chain nonce at the same time.
+#[derive(Debug)]
+pub struct WalletPool {
+    clients: Vec<Arc<SignerClient>>,
+    nonces: HashMap<Address, AtomicU64>,
+    next: AtomicUsize,
+}
+
+impl WalletPool {
+    /// Builds a pool from `wallets`, seeding each wallet's nonce cache from
+    /// `eth_getTransactionCount(address, pending)` once.
+    pub async fn new(
+        provider: Arc<Provider<Http>>,
+        wallets: Vec<LocalWallet>,
+    ) -> Result<Self, Report> {
+        let mut clients = Vec::with_capacity(wallets.len());
+        let mut nonces = HashMap::with_capacity(wallets.len());
+
+        for wallet in wallets {
+            let address = wallet.address();
+            let nonce = provider
+                .get_transaction_count(address, Some(BlockNumber::Pending.into()))
+                .await?;
+            nonces.insert(address, AtomicU64::new(nonce.as_u64()));
+            clients.push(Arc::new(SignerMiddleware::new(provider.clone(), wallet)));
+        }
+
+        Ok(WalletPool {
+            clients,
+            nonces,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// Picks the next wallet address round-robin, for callers that don't care which wallet
+    /// sends as long as load is spread across the pool.
+    pub fn round_robin_address(&self) -> Address {
+        let i = self.next.fetch_add(1, Ordering::Relaxed) % self.clients.len();
+        self.clients[i].address()
+    }
+
+    fn client_for(&self, address: Address) -> Result<&Arc<SignerClient>, Report> {
+        self.clients
+            .iter()
+            .find(|client| client.address() == address)
+            .ok_or_else(|| eyre!("wallet {:?} is not part of this pool", address))
+    }
+
+    /// Atomically allocates the next local nonce for `address`, without waiting on the chain.
+    fn next_nonce(&self, address: Address) -> Result<U256, Report> {
+        let counter = self
+            .nonces
+            .get(&address)
+            .ok_or_else(|| eyre!("wallet {:?} is not part of this pool", address))?;
+        Ok(U256::from(counter.fetch_add(1, Ordering::SeqCst)))
+    }
+
+    /// Reads the locally-cached next nonce for `address` without allocating it, e.g. to seed a
+    /// [`SenderQueue`]'s expected nonce from the wallet's actual chain/pool nonce rather than
+    /// from whichever transaction happens to be enqueued first.
+    fn current_nonce(&self, address: Address) -> Result<U256, Report> {
+        let counter = self
+            .nonces
+            .get(&address)
+            .ok_or_else(|| eyre!("wallet {:?} is not part of this pool", address))?;
+        Ok(U256::from(counter.load(Ordering::SeqCst)))
+    }
+
+    /// Re-reads `address`'s nonce from the chain and resets the local counter to it. Used when
+    /// a send comes back with a nonce-drift error (e.g. "nonce too low"/"already known"),
+    /// meaning the cached nonce is no longer trustworthy.
+    async fn resync_nonce(&self, address: Address) -> Result<U256, Report> {
+        let client = self.client_for(address)?;
+        let nonce = client
+            .get_transaction_count(address, Some(BlockNumber::Pending.into()))
+            .await?;
+        if let Some(counter) = self.nonces.get(&address) {
+            counter.store(nonce.as_u64(), Ordering::SeqCst);
+        }
+        Ok(nonce)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TransactionManager {
-    pub client: Arc<SignerMiddleware<Arc<Provider<Http>>, LocalWallet>>,
-    pub wallet: LocalWallet,
+    pub wallet_pool: Arc<WalletPool>,
     num_confirmations: usize,
+    escalation_interval: Duration,
+    escalation_bump_percent: u64,
+    escalation_max_bumps: u32,
+    escalation_price_cap: U256,
+    enable_access_list: bool,
 }
 
 impl TransactionManager {
     pub fn new(
-        provider: Arc<Provider<Http>>,
-        wallet: &LocalWallet,
+        wallet_pool: Arc<WalletPool>,
         num_confirmations: usize,
+        escalation_interval: Duration,
+        escalation_bump_percent: u64,
+        escalation_max_bumps: u32,
+        escalation_price_cap: U256,
+        enable_access_list: bool,
     ) -> Self {
-        let client = Arc::new(SignerMiddleware::new(provider, wallet.clone()));
         TransactionManager {
-            client,
-            wallet: wallet.clone(),
+            wallet_pool,
             num_confirmations,
+            escalation_interval,
+            escalation_bump_percent,
+            escalation_max_bumps,
+            escalation_price_cap,
+            enable_access_list,
         }
     }
 
-    pub async fn handle_transaction(&self, transaction: TransactionRequest) -> Result<(), Report> {
+    /// Sends `transaction` from `wallet`, or from the next wallet in the pool's round-robin
+    /// rotation if `wallet` is `None`, so high-throughput callers can parallelize across wallets
+    /// without duplicate-tx flakes.
+    pub async fn handle_transaction(
+        &self,
+        transaction: TransactionRequest,
+        wallet: Option<Address>,
+    ) -> Result<TransactionOutcome, Report> {
+        let address = wallet.unwrap_or_else(|| self.wallet_pool.round_robin_address());
+        let nonce = match transaction.nonce {
+            Some(nonce) => nonce,
+            None => self.wallet_pool.next_nonce(address)?,
+        };
+        self.send_with_nonce(transaction, address, nonce).await
+    }
+
+    /// Sends `transaction` from `address` at a caller-chosen `nonce`, retrying on transient
+    /// errors and resyncing the wallet pool's nonce cache on drift, but failing immediately on
+    /// permanent errors (see `PERMANENT_ERROR_MARKERS`) instead of burning all `MAX_RETRIES`
+    /// with backoffs. Used directly by [`TransactionQueue`], which assigns nonces itself to keep
+    /// senders' queued transactions in order.
+    async fn send_with_nonce(
+        &self,
+        transaction: TransactionRequest,
+        address: Address,
+        mut nonce: U256,
+    ) -> Result<TransactionOutcome, Report> {
+        let client = self.wallet_pool.client_for(address)?;
+
         let mut attempts = 0;
-        let mut adjust_nonce = false;
 
         while attempts < MAX_RETRIES {
-            let transaction = if adjust_nonce {
-                let num_transactions = self
-                    .client
-                    .get_transaction_count(self.get_address(), None)
-                    .await?;
-                let new_nonce = num_transactions + attempts - 2; // testing if nonce got skipped due to reorg
-                info!(
-                    "Attempt #{:?} Will retry with nonce {:?} for wallet {:?}. Chain nonce: {:?}",
-                    attempts,
-                    &new_nonce,
-                    self.get_address(),
-                    &new_nonce
-                    num_transactions
-                );
-                transaction.clone().nonce(new_nonce)
-            } else {
-                transaction.clone()
-            };
+            let mut request = transaction.clone();
+            request.nonce = Some(nonce);
+            let typed_transaction = self.build_transaction(client, request).await?;
 
-            match self.try_send_transaction(&transaction).await {
-                Ok(()) => return Ok(()),
-                Err(e) if attempts < MAX_RETRIES => {
-                    if e.to_string().contains("already known") {
+            match self.try_send_transaction(client, &typed_transaction).await {
+                Ok(outcome) => return Ok(outcome),
+                Err(e) if Self::is_permanent_error(&e) => {
+                    error!(
+                        "Permanent error sending transaction from wallet {:?}, giving up: {:?}",
+                        address, e
+                    );
+                    // The transaction was either rejected before ever reaching the chain, so the
+                    // nonce allocated for it was never consumed, or (gas escalation exhausted)
+                    // broadcast but still unconfirmed. Either way the pool's cached nonce can't be
+                    // trusted, so resync from chain instead of leaving it ahead of reality.
+                    if let Err(resync_err) = self.wallet_pool.resync_nonce(address).await {
+                        error!(
+                            "Failed to resync nonce for wallet {:?} after permanent error: {:?}",
+                            address, resync_err
+                        );
+                    }
+                    return Err(e);
+                }
+                Err(e) => {
+                    let message = e.to_string();
+                    if message.contains("already known")
+                        || message.contains("nonce too low")
+                        || message.contains("nonce too high")
+                    {
                         info!(
-                            "Transaction {:?} already known, retrying with new nonce {:?}",
-                            transaction, transaction.nonce
+                            "Nonce {:?} for wallet {:?} drifted ({:?}), resyncing from chain",
+                            nonce, address, message
                         );
-                        adjust_nonce = true;
+                        nonce = self.wallet_pool.resync_nonce(address).await?;
                     };
                     error!(
                         "Error sending transaction, retry #{:?} from wallet {:?}: {:?}",
                         attempts + 1,
-                        self.get_address(),
+                        address,
                         e,
                     );
                     sleep(RETRY_DELAY * (attempts + 1)).await;
 
                     attempts += 1;
                 }
-                Err(e) => {
-                    error!("Error sending transaction, giving up: {:?}", e);
-                    return Err(e);
-                }
             }
         }
 
-        Ok(())
+        if let Err(resync_err) = self.wallet_pool.resync_nonce(address).await {
+            error!(
+                "Failed to resync nonce for wallet {:?} after exhausting retries: {:?}",
+                address, resync_err
+            );
+        }
+
+        Err(eyre!(
+            "Gave up sending transaction from wallet {:?} after {:?} attempts",
+            address,
+            MAX_RETRIES
+        ))
+    }
+
+    /// Classifies a send error as permanent (retrying the same intent cannot help, e.g. a
+    /// revert or insufficient funds) vs transient (timeouts, "already known", nonce drift).
+    fn is_permanent_error(e: &Report) -> bool {
+        let message = e.to_string().to_lowercase();
+        PERMANENT_ERROR_MARKERS
+            .iter()
+            .any(|marker| message.contains(marker))
     }
 
-    async fn try_send_transaction(&self, transaction: &TransactionRequest) -> Result<(), Report> {
-        let estimate_gas = self.estimate_gas(transaction.clone()).await?;
+    async fn try_send_transaction(
+        &self,
+        client: &Arc<SignerClient>,
+        transaction: &TypedTransaction,
+    ) -> Result<TransactionOutcome, Report> {
+        let (mut transaction, access_list_gas_used) = if self.enable_access_list {
+            self.apply_access_list(client, transaction.clone()).await
+        } else {
+            (transaction.clone(), None)
+        };
+
+        let estimate_gas = match access_list_gas_used {
+            Some(gas_used) => gas_used,
+            None => self.estimate_gas(client, &transaction).await?,
+        };
         let increased_gas: U256 = estimate_gas
             .checked_mul(110.into())
             .unwrap_or_default()
@@ -94,48 +310,997 @@ impl TransactionManager {
             "Estimated gas: {:?}, increased gas: {:?}",
             estimate_gas, increased_gas
         );
-        let transaction = transaction.clone().gas(increased_gas);
+        transaction.set_gas(increased_gas);
 
         info!("Sending transaction {:?}", transaction);
-        match self
-            .client
-            .send_transaction(transaction.clone(), None)
-            .await
-        {
-            Ok(pending_tx) => {
-                let tx_hash = pending_tx.tx_hash();
-                info!(
-                    "Transaction {:?} sent with {:?} nonce from wallet {:?}. Waiting for confirmation...",
-                    tx_hash, transaction.nonce, self.get_address()
-                );
+        let pending_tx = match client.send_transaction(transaction.clone(), None).await {
+            Ok(pending_tx) => pending_tx,
+            Err(e) => {
+                error!("Error sending transaction: {:?}", e);
+                return Err(e.into());
+            }
+        };
+        let tx_hash = pending_tx.tx_hash();
+        info!(
+            "Transaction {:?} sent with {:?} nonce from wallet {:?}. Waiting for confirmation...",
+            tx_hash,
+            transaction.nonce(),
+            client.address()
+        );
 
-                let receipt = pending_tx
-                    .confirmations(self.num_confirmations)
-                    .await?
-                    .unwrap_or_default();
+        let outcome = self
+            .wait_with_escalation(client, transaction, tx_hash)
+            .await?;
 
-                info!(
-                    "Transaction {:?} confirmed. Block #{:?} ({:?})",
-                    tx_hash, receipt.block_number, receipt.block_hash
-                );
+        info!(
+            "Transaction {:?} confirmed with {:?} confirmations in block #{:?}, status {:?}",
+            outcome.tx_hash, outcome.confirmations, outcome.block_number, outcome.status
+        );
+
+        Ok(outcome)
+    }
+
+    /// Watches a broadcast transaction for its receipt, and every `escalation_interval` it
+    /// stays unmined, re-broadcasts the same nonce with `escalation_bump_percent` more gas (up
+    /// to `escalation_max_bumps` times, never exceeding `escalation_price_cap`). Resolves as
+    /// soon as any one of the broadcast transactions is mined. Fails once escalation options are
+    /// exhausted (either the bump count or the price cap) and the transaction is still unmined,
+    /// rather than polling forever.
+    async fn wait_with_escalation(
+        &self,
+        client: &Arc<SignerClient>,
+        mut transaction: TypedTransaction,
+        first_hash: TxHash,
+    ) -> Result<TransactionOutcome, Report> {
+        let mut broadcast_hashes = vec![first_hash];
+        let mut bumps = 0;
+        let mut waited = Duration::ZERO;
+
+        loop {
+            sleep(RECEIPT_POLL_INTERVAL).await;
+            waited += RECEIPT_POLL_INTERVAL;
+
+            for hash in broadcast_hashes.iter().rev() {
+                if let Some(receipt) = client.get_transaction_receipt(*hash).await? {
+                    return self
+                        .watch_for_reorg(client, transaction, broadcast_hashes, receipt)
+                        .await;
+                }
+            }
+
+            if waited < self.escalation_interval {
+                continue;
+            }
+            waited = Duration::ZERO;
+
+            if bumps >= self.escalation_max_bumps {
+                return Err(eyre!(
+                    "transaction with nonce {:?} still unconfirmed, gas escalation exhausted after {:?} bumps",
+                    transaction.nonce(),
+                    self.escalation_max_bumps
+                ));
+            }
+
+            if transaction.gas_price().unwrap_or_default() >= self.escalation_price_cap {
+                return Err(eyre!(
+                    "transaction with nonce {:?} still unconfirmed, gas escalation exhausted at price cap {:?}",
+                    transaction.nonce(),
+                    self.escalation_price_cap
+                ));
+            }
+
+            self.apply_bumped_gas_price(&mut transaction);
+            bumps += 1;
+            info!(
+                "Transaction with nonce {:?} stuck after {:?}s, re-broadcasting at bumped gas price (bump #{:?})",
+                transaction.nonce(),
+                self.escalation_interval.as_secs(),
+                bumps
+            );
+
+            match client.send_transaction(transaction.clone(), None).await {
+                Ok(pending_tx) => broadcast_hashes.push(pending_tx.tx_hash()),
+                Err(e) => debug!(
+                    "Gas-escalation re-broadcast rejected, still watching prior hashes: {:?}",
+                    e
+                ),
+            }
+        }
+    }
+
+    /// Bumps whichever gas-price field(s) the transaction variant carries by
+    /// `escalation_bump_percent`, capped at `escalation_price_cap`.
+    fn apply_bumped_gas_price(&self, transaction: &mut TypedTransaction) {
+        let bump = |price: U256| -> U256 {
+            price
+                .checked_mul((100 + self.escalation_bump_percent).into())
+                .unwrap_or_default()
+                .checked_div(100.into())
+                .unwrap_or_default()
+                .min(self.escalation_price_cap)
+        };
+
+        match transaction {
+            TypedTransaction::Legacy(tx) => {
+                if let Some(gas_price) = tx.gas_price {
+                    tx.gas_price = Some(bump(gas_price));
+                }
+            }
+            TypedTransaction::Eip2930(tx) => {
+                if let Some(gas_price) = tx.tx.gas_price {
+                    tx.tx.gas_price = Some(bump(gas_price));
+                }
+            }
+            TypedTransaction::Eip1559(tx) => {
+                if let Some(max_fee_per_gas) = tx.max_fee_per_gas {
+                    tx.max_fee_per_gas = Some(bump(max_fee_per_gas));
+                }
+                if let Some(max_priority_fee_per_gas) = tx.max_priority_fee_per_gas {
+                    tx.max_priority_fee_per_gas = Some(bump(max_priority_fee_per_gas));
+                }
+            }
+        }
+    }
+
+    /// Watches a mined transaction until `num_confirmations` additional blocks have been built
+    /// on top of its block. If the receipt's block hash changes or the transaction disappears
+    /// from a canonical block (a reorg), re-broadcasts it and resumes watching instead of
+    /// reporting success. A reverted transaction is still reported through the `Ok` path, with
+    /// `status: TransactionStatus::Reverted`, once it's confirmed like any other outcome — it
+    /// was mined, just not successfully, which callers need to be able to observe structurally
+    /// rather than as an opaque error.
+    async fn watch_for_reorg(
+        &self,
+        client: &Arc<SignerClient>,
+        transaction: TypedTransaction,
+        mut broadcast_hashes: Vec<TxHash>,
+        mut receipt: TransactionReceipt,
+    ) -> Result<TransactionOutcome, Report> {
+        let tx_hash = receipt.transaction_hash;
+
+        loop {
+            let mined_block = receipt
+                .block_number
+                .ok_or_else(|| eyre!("mined transaction {:?} has no block number", tx_hash))?;
+            let mined_block_hash = receipt.block_hash;
+
+            let latest_block = client.get_block_number().await?;
+            let confirmations = latest_block.saturating_sub(mined_block).as_u64() as usize;
+
+            if confirmations >= self.num_confirmations {
+                let status = if receipt.status == Some(U64::zero()) {
+                    TransactionStatus::Reverted
+                } else {
+                    TransactionStatus::Success
+                };
+                return Ok(TransactionOutcome {
+                    tx_hash,
+                    block_number: mined_block,
+                    confirmations,
+                    status,
+                });
+            }
+
+            sleep(RECEIPT_POLL_INTERVAL).await;
+
+            match client.get_transaction_receipt(tx_hash).await? {
+                Some(current_receipt) if current_receipt.block_hash == mined_block_hash => {
+                    receipt = current_receipt;
+                }
+                _ => {
+                    info!(
+                        "Reorg detected: transaction {:?} left block {:?}, re-broadcasting",
+                        tx_hash, mined_block_hash
+                    );
+                    let pending_tx = client.send_transaction(transaction.clone(), None).await?;
+                    broadcast_hashes.push(pending_tx.tx_hash());
+                    receipt = self.await_any_receipt(client, &broadcast_hashes).await?;
+                }
+            }
+        }
+    }
+
+    /// Polls until any of `hashes` has a receipt.
+    async fn await_any_receipt(
+        &self,
+        client: &Arc<SignerClient>,
+        hashes: &[TxHash],
+    ) -> Result<TransactionReceipt, Report> {
+        loop {
+            for hash in hashes.iter().rev() {
+                if let Some(receipt) = client.get_transaction_receipt(*hash).await? {
+                    return Ok(receipt);
+                }
+            }
+            sleep(RECEIPT_POLL_INTERVAL).await;
+        }
+    }
+
+    pub async fn estimate_gas(
+        &self,
+        client: &Arc<SignerClient>,
+        transaction: &TypedTransaction,
+    ) -> Result<U256, Report> {
+        Ok(client.estimate_gas(transaction, None).await?)
+    }
+
+    /// Calls `eth_createAccessList` for `transaction` and attaches the returned access list,
+    /// upgrading a legacy transaction to EIP-2930 to carry it (EIP-1559 transactions already
+    /// have an access-list field). Returns the transaction and the RPC's refined `gasUsed`, to
+    /// be used in place of `eth_estimateGas`. Falls back to the original transaction and no gas
+    /// hint on any error, since not every node supports this method.
+    async fn apply_access_list(
+        &self,
+        client: &Arc<SignerClient>,
+        transaction: TypedTransaction,
+    ) -> (TypedTransaction, Option<U256>) {
+        match client.create_access_list(&transaction, None).await {
+            Ok(access_list_with_gas) => {
+                let transaction = match transaction {
+                    TypedTransaction::Legacy(tx) => {
+                        TypedTransaction::Eip2930(Eip2930TransactionRequest {
+                            tx,
+                            access_list: access_list_with_gas.access_list,
+                        })
+                    }
+                    TypedTransaction::Eip2930(mut tx) => {
+                        tx.access_list = access_list_with_gas.access_list;
+                        TypedTransaction::Eip2930(tx)
+                    }
+                    TypedTransaction::Eip1559(mut tx) => {
+                        tx.access_list = access_list_with_gas.access_list;
+                        TypedTransaction::Eip1559(tx)
+                    }
+                };
+                (transaction, Some(access_list_with_gas.gas_used))
             }
             Err(e) => {
-                error!("Error sending transaction: {:?}", e);
-                return Err(e.into());
+                debug!(
+                    "eth_createAccessList unsupported or failed, falling back to plain gas estimation: {:?}",
+                    e
+                );
+                (transaction, None)
             }
         }
+    }
+
+    /// Builds a `TypedTransaction` from the given request, picking EIP-1559 on chains whose
+    /// latest block exposes a base fee and falling back to a legacy transaction otherwise.
+    async fn build_transaction(
+        &self,
+        client: &Arc<SignerClient>,
+        transaction: TransactionRequest,
+    ) -> Result<TypedTransaction, Report> {
+        if self.supports_eip1559(client).await.unwrap_or(false) {
+            let (max_priority_fee_per_gas, max_fee_per_gas) =
+                self.estimate_eip1559_fees(client).await?;
+            info!(
+                "Using EIP-1559 transaction: maxPriorityFeePerGas {:?}, maxFeePerGas {:?}",
+                max_priority_fee_per_gas, max_fee_per_gas
+            );
+            let eip1559_transaction = Eip1559TransactionRequest {
+                from: transaction.from,
+                to: transaction.to,
+                gas: transaction.gas,
+                value: transaction.value,
+                data: transaction.data,
+                nonce: transaction.nonce,
+                access_list: Default::default(),
+                max_priority_fee_per_gas: Some(max_priority_fee_per_gas),
+                max_fee_per_gas: Some(max_fee_per_gas),
+                chain_id: transaction.chain_id,
+            };
+            Ok(TypedTransaction::Eip1559(eip1559_transaction))
+        } else {
+            let mut transaction = transaction;
+            if transaction.gas_price.is_none() {
+                // The gas-price escalator only bumps a price already present on the local
+                // `TypedTransaction`; left unset, it's filled remotely by the middleware on its
+                // own copy of the transaction, which we never see. Capture it here so
+                // `apply_bumped_gas_price` has something to bump.
+                transaction.gas_price = Some(client.get_gas_price().await?);
+            }
+            Ok(TypedTransaction::Legacy(transaction))
+        }
+    }
+
+    /// Checks whether the chain has activated London by looking for `baseFeePerGas` on the
+    /// latest block.
+    async fn supports_eip1559(&self, client: &Arc<SignerClient>) -> Result<bool, Report> {
+        let latest_block = client
+            .get_block(BlockNumber::Latest)
+            .await?
+            .ok_or_else(|| eyre!("failed to fetch latest block"))?;
+        Ok(latest_block.base_fee_per_gas.is_some())
+    }
 
+    /// Estimates `maxPriorityFeePerGas`/`maxFeePerGas` from `eth_feeHistory`: the tip is the
+    /// median of the per-block reward at `FEE_HISTORY_REWARD_PERCENTILE`, and the fee cap is
+    /// twice the latest base fee plus that tip, to tolerate base-fee growth over a few blocks.
+    async fn estimate_eip1559_fees(
+        &self,
+        client: &Arc<SignerClient>,
+    ) -> Result<(U256, U256), Report> {
+        let fee_history = client
+            .fee_history(
+                FEE_HISTORY_BLOCKS,
+                BlockNumber::Latest,
+                &[FEE_HISTORY_REWARD_PERCENTILE],
+            )
+            .await?;
+
+        let rewards: Vec<U256> = fee_history
+            .reward
+            .iter()
+            .filter_map(|block_rewards| block_rewards.first().copied())
+            .collect();
+        let max_priority_fee_per_gas = median_reward(rewards);
+
+        let base_fee = *fee_history
+            .base_fee_per_gas
+            .last()
+            .ok_or_else(|| eyre!("eth_feeHistory returned no base fee"))?;
+        let max_fee_per_gas = fee_cap(base_fee, max_priority_fee_per_gas);
+
+        Ok((max_priority_fee_per_gas, max_fee_per_gas))
+    }
+}
+
+/// The median of a block range's per-block priority-fee reward, used as `maxPriorityFeePerGas`.
+fn median_reward(mut rewards: Vec<U256>) -> U256 {
+    rewards.sort();
+    rewards
+        .get(rewards.len() / 2)
+        .copied()
+        .unwrap_or_else(U256::zero)
+}
+
+/// `maxFeePerGas` for a given base fee and tip: twice the base fee plus the tip, to tolerate
+/// base-fee growth over the next few blocks.
+fn fee_cap(base_fee: U256, priority_fee: U256) -> U256 {
+    base_fee
+        .checked_mul(2.into())
+        .unwrap_or_default()
+        .checked_add(priority_fee)
+        .unwrap_or_default()
+}
+
+/// A transaction waiting in a [`TransactionQueue`], scored by its effective gas price so the
+/// queue can tell which entries are worth keeping when it's full.
+#[derive(Debug, Clone)]
+pub struct QueuedTransaction {
+    pub sender: Address,
+    pub nonce: U256,
+    pub request: TransactionRequest,
+    pub effective_gas_price: U256,
+    attempts: u32,
+}
+
+/// One sender's view of the queue: `ready` holds the contiguous run of transactions starting at
+/// `expected_nonce` (dispatchable in nonce order), `future` holds transactions with a nonce gap
+/// that aren't dispatchable yet.
+#[derive(Debug, Default)]
+struct SenderQueue {
+    expected_nonce: Option<U256>,
+    ready: BTreeMap<U256, QueuedTransaction>,
+    future: BTreeMap<U256, QueuedTransaction>,
+}
+
+impl SenderQueue {
+    /// Starts a fresh queue for a sender whose next expected nonce is already known (from the
+    /// wallet pool's cache or the chain), so the ready/future split is correct from the first
+    /// insert regardless of what order transactions are enqueued in.
+    fn new(expected_nonce: U256) -> Self {
+        SenderQueue {
+            expected_nonce: Some(expected_nonce),
+            ready: BTreeMap::new(),
+            future: BTreeMap::new(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.ready.len() + self.future.len()
+    }
+
+    /// Inserts `entry` into `ready` if it continues the contiguous run from `expected_nonce`,
+    /// or `future` if it leaves a gap, then promotes any now-contiguous future entries.
+    /// `expected_nonce` must already be seeded (via `new`) from the wallet's actual nonce, not
+    /// derived here, or out-of-order enqueues would corrupt the ready/future split.
+    fn insert(&mut self, entry: QueuedTransaction) {
+        let expected = self.expected_nonce.unwrap_or(entry.nonce);
+        let frontier = expected + U256::from(self.ready.len());
+        if entry.nonce == frontier {
+            self.ready.insert(entry.nonce, entry);
+            self.promote_from_future();
+        } else {
+            self.future.insert(entry.nonce, entry);
+        }
+    }
+
+    fn promote_from_future(&mut self) {
+        let expected = self.expected_nonce.unwrap_or_default();
+        while let Some(entry) = self
+            .future
+            .remove(&(expected + U256::from(self.ready.len())))
+        {
+            let frontier = expected + U256::from(self.ready.len());
+            self.ready.insert(frontier, entry);
+        }
+    }
+
+    /// Marks `nonce` confirmed: advances the frontier and promotes any future entry that's now
+    /// contiguous. `nonce` is assumed already removed from `ready` by the caller.
+    fn confirm(&mut self, nonce: U256) {
+        self.expected_nonce = Some(nonce + 1);
+        self.promote_from_future();
+    }
+
+    /// Resets the frontier to `expected_nonce` (the wallet's authoritative chain nonce). Unlike
+    /// `confirm`, this does not assume the abandoned nonce was consumed — use it when a dispatch
+    /// is dropped before ever reaching the chain, so bookkeeping can't tell whether the nonce is
+    /// still available. A resync can reopen a gap below transactions that were previously
+    /// promoted into `ready`, so rather than just promoting from `future`, everything is merged
+    /// and the contiguous run from `expected_nonce` is recomputed from scratch; entries below
+    /// `expected_nonce` are discarded as stale.
+    fn resync(&mut self, expected_nonce: U256) {
+        self.expected_nonce = Some(expected_nonce);
+
+        let mut all = std::mem::take(&mut self.ready);
+        all.append(&mut self.future);
+        self.future = all.split_off(&expected_nonce);
+
+        self.promote_from_future();
+    }
+
+    fn lowest_scored(&self) -> Option<(U256, U256)> {
+        self.ready
+            .values()
+            .chain(self.future.values())
+            .map(|tx| (tx.nonce, tx.effective_gas_price))
+            .min_by_key(|(_, score)| *score)
+    }
+
+    fn remove(&mut self, nonce: U256) {
+        self.ready.remove(&nonce);
+        self.future.remove(&nonce);
+    }
+}
+
+/// A prioritized transaction queue sitting in front of [`TransactionManager::handle_transaction`],
+/// modeled on a mempool: each sender has a *ready* set (nonce-contiguous from the chain's next
+/// expected nonce) and a *future* set (nonce has a gap). `drain` dispatches the highest-scored
+/// ready transaction across all senders, and promotes future transactions as nonces confirm.
+#[derive(Debug)]
+pub struct TransactionQueue {
+    manager: Arc<TransactionManager>,
+    max_total: usize,
+    max_per_sender: usize,
+    senders: Mutex<HashMap<Address, SenderQueue>>,
+}
+
+impl TransactionQueue {
+    pub fn new(manager: Arc<TransactionManager>, max_total: usize) -> Self {
+        Self::with_per_sender_share(manager, max_total, DEFAULT_PER_SENDER_SHARE)
+    }
+
+    pub fn with_per_sender_share(
+        manager: Arc<TransactionManager>,
+        max_total: usize,
+        per_sender_share: f64,
+    ) -> Self {
+        let max_per_sender = ((max_total as f64 * per_sender_share).ceil() as usize).max(1);
+        TransactionQueue {
+            manager,
+            max_total,
+            max_per_sender,
+            senders: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub async fn pending_count(&self) -> usize {
+        self.senders
+            .lock()
+            .await
+            .values()
+            .map(SenderQueue::len)
+            .sum()
+    }
+
+    /// Queues `request` (which must already carry the nonce it's meant to occupy) for `sender`,
+    /// scoring it by its `gas_price`. If the sender or the whole queue is at capacity, the
+    /// lowest-scored entry is evicted to make room, but only if `request` outscores it;
+    /// otherwise `request` is dropped in favor of keeping the queue's existing, more valuable
+    /// work.
+    pub async fn enqueue(
+        &self,
+        sender: Address,
+        request: TransactionRequest,
+    ) -> Result<(), Report> {
+        let nonce = request
+            .nonce
+            .ok_or_else(|| eyre!("queued transaction must have an explicit nonce"))?;
+        let effective_gas_price = request.gas_price.unwrap_or_default();
+        let entry = QueuedTransaction {
+            sender,
+            nonce,
+            request,
+            effective_gas_price,
+            attempts: 0,
+        };
+
+        let mut senders = self.senders.lock().await;
+
+        if !senders.contains_key(&sender) {
+            let expected_nonce = self.manager.wallet_pool.current_nonce(sender)?;
+            senders.insert(sender, SenderQueue::new(expected_nonce));
+        }
+
+        let sender_len = senders.get(&sender).map(SenderQueue::len).unwrap_or(0);
+        if sender_len >= self.max_per_sender
+            && !Self::evict_lowest(&mut senders, Some(sender), &entry)
+        {
+            debug!(
+                "Queued transaction for {:?} at nonce {:?} scored too low to displace {:?}'s existing work, dropping",
+                sender, nonce, sender
+            );
+            return Ok(());
+        }
+
+        let total: usize = senders.values().map(SenderQueue::len).sum();
+        if total >= self.max_total && !Self::evict_lowest(&mut senders, None, &entry) {
+            debug!(
+                "Queue at global cap ({:?}), transaction for {:?} at nonce {:?} scored too low to displace existing work, dropping",
+                self.max_total, sender, nonce
+            );
+            return Ok(());
+        }
+
+        senders
+            .get_mut(&sender)
+            .expect("sender queue seeded above")
+            .insert(entry);
         Ok(())
     }
 
-    pub fn get_address(&self) -> Address {
-        self.wallet.address()
+    /// Evicts the lowest-scored entry (restricted to `sender` if given, otherwise across all
+    /// senders) if `candidate` outscores it. Returns whether room was made.
+    fn evict_lowest(
+        senders: &mut HashMap<Address, SenderQueue>,
+        sender: Option<Address>,
+        candidate: &QueuedTransaction,
+    ) -> bool {
+        let lowest = match sender {
+            Some(addr) => senders
+                .get(&addr)
+                .and_then(SenderQueue::lowest_scored)
+                .map(|(nonce, score)| (addr, nonce, score)),
+            None => senders
+                .iter()
+                .filter_map(|(addr, queue)| {
+                    queue
+                        .lowest_scored()
+                        .map(|(nonce, score)| (*addr, nonce, score))
+                })
+                .min_by_key(|(_, _, score)| *score),
+        };
+
+        let Some((addr, nonce, score)) = lowest else {
+            return true; // nothing queued yet to compare against
+        };
+        if candidate.effective_gas_price <= score {
+            return false;
+        }
+        if let Some(queue) = senders.get_mut(&addr) {
+            queue.remove(nonce);
+        }
+        true
+    }
+
+    /// Repeatedly dispatches the highest-scored ready transaction from any sender into
+    /// [`TransactionManager::send_with_nonce`], promoting future transactions as nonces
+    /// confirm, until the queue runs dry. A transaction that reverted on-chain, failed for a
+    /// permanent reason (see `TransactionManager::is_permanent_error`), or has already failed
+    /// `QUEUE_MAX_DISPATCH_ATTEMPTS` dispatches is dropped instead of redispatched, so the queue
+    /// can't get stuck hammering the RPC on a transaction that can never succeed.
+    pub async fn drain(&self) {
+        while let Some(mut entry) = self.pop_highest_ready().await {
+            let result = self
+                .manager
+                .send_with_nonce(entry.request.clone(), entry.sender, entry.nonce)
+                .await;
+
+            match result {
+                Ok(outcome) => {
+                    if outcome.status == TransactionStatus::Reverted {
+                        error!(
+                            "Queued transaction for {:?} at nonce {:?} reverted on-chain (tx {:?})",
+                            entry.sender, entry.nonce, outcome.tx_hash
+                        );
+                    }
+                    let mut senders = self.senders.lock().await;
+                    if let Some(queue) = senders.get_mut(&entry.sender) {
+                        queue.confirm(entry.nonce);
+                    }
+                }
+                Err(e) => {
+                    entry.attempts += 1;
+                    if TransactionManager::is_permanent_error(&e)
+                        || entry.attempts >= QUEUE_MAX_DISPATCH_ATTEMPTS
+                    {
+                        error!(
+                            "Queued transaction for {:?} at nonce {:?} failed after {:?} attempt(s), dropping: {:?}",
+                            entry.sender, entry.nonce, entry.attempts, e
+                        );
+                        // A dropped entry may never have reached the chain, so its nonce can't
+                        // be assumed consumed like `confirm` does; resync against the wallet's
+                        // actual chain nonce instead of blindly advancing past it. `send_with_nonce`
+                        // already attempts this resync before returning its error, but that attempt
+                        // is best-effort and only logs on failure, so the pool's cache may still be
+                        // stale here — resync from chain again rather than trusting it.
+                        match self.manager.wallet_pool.resync_nonce(entry.sender).await {
+                            Ok(actual_nonce) => {
+                                let mut senders = self.senders.lock().await;
+                                if let Some(queue) = senders.get_mut(&entry.sender) {
+                                    queue.resync(actual_nonce);
+                                }
+                            }
+                            Err(resync_err) => error!(
+                                "Failed to resync nonce for {:?} after dropping queued transaction at nonce {:?}: {:?}",
+                                entry.sender, entry.nonce, resync_err
+                            ),
+                        }
+                    } else {
+                        debug!(
+                            "Queued transaction for {:?} at nonce {:?} failed (attempt {:?}/{:?}), re-queueing: {:?}",
+                            entry.sender, entry.nonce, entry.attempts, QUEUE_MAX_DISPATCH_ATTEMPTS, e
+                        );
+                        let mut senders = self.senders.lock().await;
+                        senders
+                            .entry(entry.sender)
+                            .or_default()
+                            .ready
+                            .insert(entry.nonce, entry);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Pops the dispatchable (lowest-nonce) ready transaction of whichever sender currently has
+    /// the highest-scored one, since a sender's own nonces must still be sent in order.
+    async fn pop_highest_ready(&self) -> Option<QueuedTransaction> {
+        let mut senders = self.senders.lock().await;
+        let (address, nonce, _) = senders
+            .iter()
+            .filter_map(|(addr, queue)| {
+                queue
+                    .ready
+                    .iter()
+                    .next()
+                    .map(|(&nonce, tx)| (*addr, nonce, tx.effective_gas_price))
+            })
+            .max_by_key(|(_, _, score)| *score)?;
+        senders
+            .get_mut(&address)
+            .and_then(|queue| queue.ready.remove(&nonce))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wallet_pool_with_nonce(address: Address, nonce: u64) -> WalletPool {
+        let mut nonces = HashMap::new();
+        nonces.insert(address, AtomicU64::new(nonce));
+        WalletPool {
+            clients: Vec::new(),
+            nonces,
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    #[test]
+    fn next_nonce_allocates_sequentially() {
+        let address = Address::zero();
+        let pool = wallet_pool_with_nonce(address, 5);
+
+        assert_eq!(pool.next_nonce(address).unwrap(), U256::from(5));
+        assert_eq!(pool.next_nonce(address).unwrap(), U256::from(6));
+        assert_eq!(pool.next_nonce(address).unwrap(), U256::from(7));
+    }
+
+    #[test]
+    fn current_nonce_does_not_consume_the_allocation() {
+        let address = Address::zero();
+        let pool = wallet_pool_with_nonce(address, 5);
+
+        assert_eq!(pool.current_nonce(address).unwrap(), U256::from(5));
+        assert_eq!(pool.current_nonce(address).unwrap(), U256::from(5));
+        assert_eq!(pool.next_nonce(address).unwrap(), U256::from(5));
+    }
+
+    #[test]
+    fn next_nonce_errors_for_unknown_wallet() {
+        let pool = wallet_pool_with_nonce(Address::zero(), 0);
+        assert!(pool.next_nonce(Address::repeat_byte(1)).is_err());
     }
 
-    pub async fn estimate_gas(&self, transaction: TransactionRequest) -> Result<U256, Report> {
-        Ok(self
-            .client
-            .estimate_gas(&TypedTransaction::Legacy(transaction.clone()), None)
-            .await?)
+    fn queued(sender: Address, nonce: U256) -> QueuedTransaction {
+        QueuedTransaction {
+            sender,
+            nonce,
+            request: TransactionRequest::new().nonce(nonce),
+            effective_gas_price: U256::zero(),
+            attempts: 0,
+        }
+    }
+
+    #[test]
+    fn sender_queue_seeds_expected_nonce_from_the_wallet_pool_not_the_first_insert() {
+        // Regression for enqueuing out of order: if `expected_nonce` were seeded from whichever
+        // transaction is inserted first (nonce 10 here), nonces 8/9 would never become ready.
+        let mut queue = SenderQueue::new(U256::from(8));
+
+        queue.insert(queued(Address::zero(), U256::from(10)));
+        queue.insert(queued(Address::zero(), U256::from(8)));
+        queue.insert(queued(Address::zero(), U256::from(9)));
+
+        let ready_nonces: Vec<U256> = queue.ready.keys().copied().collect();
+        assert_eq!(
+            ready_nonces,
+            vec![U256::from(8), U256::from(9), U256::from(10)]
+        );
+        assert!(queue.future.is_empty());
+    }
+
+    #[test]
+    fn sender_queue_holds_a_gapped_nonce_in_future_until_it_is_filled() {
+        let mut queue = SenderQueue::new(U256::zero());
+
+        queue.insert(queued(Address::zero(), U256::from(2)));
+        assert!(queue.ready.is_empty());
+        assert_eq!(queue.future.len(), 1);
+
+        queue.insert(queued(Address::zero(), U256::zero()));
+        queue.insert(queued(Address::zero(), U256::from(1)));
+        assert_eq!(queue.ready.len(), 3);
+        assert!(queue.future.is_empty());
+    }
+
+    #[test]
+    fn sender_queue_confirm_advances_frontier_and_promotes_future() {
+        let mut queue = SenderQueue::new(U256::zero());
+        queue.insert(queued(Address::zero(), U256::zero()));
+        queue.insert(queued(Address::zero(), U256::from(1)));
+        queue.ready.remove(&U256::zero());
+
+        queue.confirm(U256::zero());
+
+        assert_eq!(
+            queue.ready.keys().copied().collect::<Vec<_>>(),
+            vec![U256::from(1)]
+        );
+    }
+
+    #[test]
+    fn median_reward_picks_the_middle_of_sorted_rewards() {
+        let rewards = vec![U256::from(5), U256::from(1), U256::from(3)];
+        assert_eq!(median_reward(rewards), U256::from(3));
+    }
+
+    #[test]
+    fn median_reward_of_empty_rewards_is_zero() {
+        assert_eq!(median_reward(Vec::new()), U256::zero());
+    }
+
+    #[test]
+    fn fee_cap_is_twice_the_base_fee_plus_the_priority_fee() {
+        assert_eq!(fee_cap(U256::from(100), U256::from(10)), U256::from(210));
+    }
+
+    #[test]
+    fn sender_queue_resync_does_not_treat_the_dropped_nonce_as_consumed() {
+        // Regression: a transaction enqueued at nonce 5 that failed before ever reaching the
+        // chain must not advance the frontier past it the way `confirm` would, since the chain's
+        // real next nonce is still 5.
+        let mut queue = SenderQueue::new(U256::from(5));
+        queue.insert(queued(Address::zero(), U256::from(5)));
+        queue.ready.remove(&U256::from(5));
+
+        queue.resync(U256::from(5));
+
+        assert_eq!(queue.expected_nonce, Some(U256::from(5)));
+    }
+
+    #[test]
+    fn sender_queue_resync_demotes_ready_entries_above_a_reopened_gap() {
+        // Nonces 5 and 6 were both promoted to `ready`, but nonce 5's dispatch is later
+        // discovered to have never reached the chain and is dropped without being re-queued.
+        // Resyncing back to 5 reopens that gap, so nonce 6 can't still count as `ready` — it
+        // must be demoted to `future` until something actually fills nonce 5 again.
+        let mut queue = SenderQueue::new(U256::from(5));
+        queue.insert(queued(Address::zero(), U256::from(5)));
+        queue.insert(queued(Address::zero(), U256::from(6)));
+        queue.ready.remove(&U256::from(5));
+        assert!(queue.ready.contains_key(&U256::from(6)));
+
+        queue.resync(U256::from(5));
+
+        assert_eq!(queue.expected_nonce, Some(U256::from(5)));
+        assert!(!queue.ready.contains_key(&U256::from(6)));
+        assert!(queue.future.contains_key(&U256::from(6)));
+    }
+
+    #[test]
+    fn is_permanent_error_matches_known_markers_case_insensitively() {
+        assert!(TransactionManager::is_permanent_error(&eyre!(
+            "Execution REVERTED"
+        )));
+        assert!(TransactionManager::is_permanent_error(&eyre!(
+            "insufficient funds for gas * price + value"
+        )));
+        assert!(!TransactionManager::is_permanent_error(&eyre!(
+            "nonce too low"
+        )));
+        assert!(!TransactionManager::is_permanent_error(&eyre!(
+            "nonce too high"
+        )));
+        assert!(!TransactionManager::is_permanent_error(&eyre!(
+            "connection timed out"
+        )));
+    }
+
+    fn queued_with_price(sender: Address, nonce: U256, price: u64) -> QueuedTransaction {
+        QueuedTransaction {
+            sender,
+            nonce,
+            request: TransactionRequest::new().nonce(nonce),
+            effective_gas_price: U256::from(price),
+            attempts: 0,
+        }
+    }
+
+    #[test]
+    fn evict_lowest_removes_the_lowest_scored_entry_when_outscored() {
+        let sender = Address::zero();
+        let mut queue = SenderQueue::new(U256::zero());
+        queue.insert(queued_with_price(sender, U256::zero(), 10));
+        let mut senders = HashMap::new();
+        senders.insert(sender, queue);
+
+        let candidate = queued_with_price(sender, U256::from(1), 20);
+        assert!(TransactionQueue::evict_lowest(
+            &mut senders,
+            Some(sender),
+            &candidate
+        ));
+        assert!(senders[&sender].ready.is_empty());
+    }
+
+    #[test]
+    fn evict_lowest_keeps_existing_work_when_candidate_does_not_outscore_it() {
+        let sender = Address::zero();
+        let mut queue = SenderQueue::new(U256::zero());
+        queue.insert(queued_with_price(sender, U256::zero(), 10));
+        let mut senders = HashMap::new();
+        senders.insert(sender, queue);
+
+        let candidate = queued_with_price(sender, U256::from(1), 5);
+        assert!(!TransactionQueue::evict_lowest(
+            &mut senders,
+            Some(sender),
+            &candidate
+        ));
+        assert_eq!(senders[&sender].ready.len(), 1);
+    }
+
+    #[test]
+    fn evict_lowest_across_all_senders_picks_the_globally_lowest_score() {
+        let low_sender = Address::repeat_byte(1);
+        let high_sender = Address::repeat_byte(2);
+        let mut senders = HashMap::new();
+
+        let mut low_queue = SenderQueue::new(U256::zero());
+        low_queue.insert(queued_with_price(low_sender, U256::zero(), 1));
+        senders.insert(low_sender, low_queue);
+
+        let mut high_queue = SenderQueue::new(U256::zero());
+        high_queue.insert(queued_with_price(high_sender, U256::zero(), 100));
+        senders.insert(high_sender, high_queue);
+
+        let candidate = queued_with_price(low_sender, U256::from(1), 50);
+        assert!(TransactionQueue::evict_lowest(
+            &mut senders,
+            None,
+            &candidate
+        ));
+        assert!(senders[&low_sender].ready.is_empty());
+        assert_eq!(senders[&high_sender].ready.len(), 1);
+    }
+
+    #[test]
+    fn evict_lowest_allows_insertion_when_nothing_is_queued_yet() {
+        let mut senders = HashMap::new();
+        let candidate = queued_with_price(Address::zero(), U256::zero(), 1);
+        assert!(TransactionQueue::evict_lowest(
+            &mut senders,
+            None,
+            &candidate
+        ));
+    }
+
+    fn manager_with_escalation(bump_percent: u64, price_cap: U256) -> TransactionManager {
+        let wallet_pool = Arc::new(wallet_pool_with_nonce(Address::zero(), 0));
+        TransactionManager::new(
+            wallet_pool,
+            1,
+            Duration::from_secs(1),
+            bump_percent,
+            5,
+            price_cap,
+            false,
+        )
+    }
+
+    #[test]
+    fn apply_bumped_gas_price_bumps_legacy_gas_price_and_respects_cap() {
+        let manager = manager_with_escalation(10, U256::from(105));
+        let mut tx = TypedTransaction::Legacy(TransactionRequest::new().gas_price(U256::from(100)));
+
+        manager.apply_bumped_gas_price(&mut tx);
+
+        assert_eq!(tx.gas_price(), Some(U256::from(105)));
+    }
+
+    #[test]
+    fn apply_bumped_gas_price_is_a_no_op_when_gas_price_is_unset() {
+        // This is exactly why `build_transaction` must fill in `gas_price` for legacy
+        // transactions before escalation begins: this function only ever bumps a price that's
+        // already `Some(..)`.
+        let manager = manager_with_escalation(10, U256::from(1_000_000));
+        let mut tx = TypedTransaction::Legacy(TransactionRequest::new());
+
+        manager.apply_bumped_gas_price(&mut tx);
+
+        assert_eq!(tx.gas_price(), None);
+    }
+
+    #[test]
+    fn apply_bumped_gas_price_bumps_eip2930_gas_price() {
+        let manager = manager_with_escalation(10, U256::from(1_000_000));
+        let mut tx = TypedTransaction::Eip2930(Eip2930TransactionRequest {
+            tx: TransactionRequest::new().gas_price(U256::from(100)),
+            access_list: Default::default(),
+        });
+
+        manager.apply_bumped_gas_price(&mut tx);
+
+        match tx {
+            TypedTransaction::Eip2930(inner) => {
+                assert_eq!(inner.tx.gas_price, Some(U256::from(110)));
+            }
+            _ => panic!("expected an EIP-2930 transaction"),
+        }
+    }
+
+    #[test]
+    fn apply_bumped_gas_price_bumps_both_eip1559_fee_fields() {
+        let manager = manager_with_escalation(10, U256::from(1_000_000));
+        let mut tx = TypedTransaction::Eip1559(
+            Eip1559TransactionRequest::new()
+                .max_fee_per_gas(U256::from(200))
+                .max_priority_fee_per_gas(U256::from(20)),
+        );
+
+        manager.apply_bumped_gas_price(&mut tx);
+
+        match tx {
+            TypedTransaction::Eip1559(inner) => {
+                assert_eq!(inner.max_fee_per_gas, Some(U256::from(220)));
+                assert_eq!(inner.max_priority_fee_per_gas, Some(U256::from(22)));
+            }
+            _ => panic!("expected an EIP-1559 transaction"),
+        }
     }
 }